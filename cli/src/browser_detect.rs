@@ -0,0 +1,246 @@
+use std::env;
+use std::path::PathBuf;
+
+/// Release channel of a Chromium-family browser, used to bias which
+/// candidate `detect()` prefers when several are installed side by side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserChannel {
+    Stable,
+    Beta,
+    Dev,
+    Canary,
+    Chromium,
+}
+
+impl BrowserChannel {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "stable" => Some(Self::Stable),
+            "beta" => Some(Self::Beta),
+            "dev" => Some(Self::Dev),
+            "canary" => Some(Self::Canary),
+            "chromium" => Some(Self::Chromium),
+            _ => None,
+        }
+    }
+}
+
+/// Candidate binary names to look for on `$PATH`, ordered by channel
+/// preference. Edge and Brave are included as fallbacks since they are
+/// Chromium-based and speak the same CDP protocol.
+fn linux_candidates(channel: Option<BrowserChannel>) -> Vec<&'static str> {
+    match channel {
+        Some(BrowserChannel::Chromium) => vec!["chromium", "chromium-browser"],
+        Some(BrowserChannel::Beta) => vec!["google-chrome-beta", "google-chrome", "chromium"],
+        Some(BrowserChannel::Dev) => vec!["google-chrome-unstable", "google-chrome", "chromium"],
+        Some(BrowserChannel::Canary) => vec!["google-chrome-canary", "google-chrome", "chromium"],
+        _ => vec![
+            "google-chrome-stable",
+            "google-chrome",
+            "chromium",
+            "chromium-browser",
+            "chrome",
+            "microsoft-edge",
+            "brave-browser",
+        ],
+    }
+}
+
+fn linux_standard_paths(channel: Option<BrowserChannel>) -> Vec<PathBuf> {
+    linux_candidates(channel)
+        .into_iter()
+        .flat_map(|name| {
+            vec![
+                PathBuf::from("/usr/bin").join(name),
+                PathBuf::from("/usr/local/bin").join(name),
+                PathBuf::from("/snap/bin").join(name),
+                PathBuf::from("/opt/google/chrome").join(name),
+            ]
+        })
+        .collect()
+}
+
+fn macos_candidates(channel: Option<BrowserChannel>) -> Vec<PathBuf> {
+    let apps: Vec<(&str, &str)> = match channel {
+        Some(BrowserChannel::Beta) => vec![("Google Chrome Beta", "Google Chrome Beta")],
+        Some(BrowserChannel::Dev) => vec![("Google Chrome Dev", "Google Chrome Dev")],
+        Some(BrowserChannel::Canary) => vec![("Google Chrome Canary", "Google Chrome Canary")],
+        Some(BrowserChannel::Chromium) => vec![("Chromium", "Chromium")],
+        _ => vec![
+            ("Google Chrome", "Google Chrome"),
+            ("Chromium", "Chromium"),
+            ("Microsoft Edge", "Microsoft Edge"),
+            ("Brave Browser", "Brave Browser"),
+        ],
+    };
+    apps.into_iter()
+        .map(|(app, binary)| {
+            PathBuf::from("/Applications")
+                .join(format!("{app}.app"))
+                .join("Contents/MacOS")
+                .join(binary)
+        })
+        .collect()
+}
+
+fn windows_registry_keys(channel: Option<BrowserChannel>) -> Vec<&'static str> {
+    match channel {
+        Some(BrowserChannel::Chromium) => vec![
+            r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\chromium.exe",
+        ],
+        _ => vec![
+            r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\chrome.exe",
+            r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\msedge.exe",
+        ],
+    }
+}
+
+/// Parses the `(Default) REG_SZ <value>` data line out of `reg query /ve`
+/// output. The columns are whitespace-padded and the value itself can
+/// contain spaces (e.g. `C:\Program Files\...`), so the only reliable
+/// anchor is the `REG_SZ` type column itself — stripping a `(Default)`
+/// prefix leaves the `REG_SZ` token still attached to the front of the
+/// value.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_reg_query_output(stdout: &str) -> Option<PathBuf> {
+    stdout.lines().find_map(|line| {
+        line.split("REG_SZ")
+            .nth(1)
+            .map(|value| PathBuf::from(value.trim()))
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn read_registry_app_path(key: &str) -> Option<PathBuf> {
+    use std::process::Command;
+
+    // Queried via `reg query` rather than a registry crate dependency, so
+    // detection keeps working on a bare `cargo build` without extra crates.
+    let output = Command::new("reg")
+        .args(["query", &format!("HKLM\\{key}"), "/ve"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_reg_query_output(&stdout)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn read_registry_app_path(_key: &str) -> Option<PathBuf> {
+    None
+}
+
+fn which(binary: &str) -> Option<PathBuf> {
+    let path_var = env::var_os("PATH")?;
+    env::split_paths(&path_var).find_map(|dir| {
+        let candidate = dir.join(binary);
+        if candidate.is_file() {
+            Some(candidate)
+        } else {
+            None
+        }
+    })
+}
+
+/// Auto-detects an installed Chrome/Chromium-family browser when no
+/// `executable_path` was supplied via flag, env var, or config.
+///
+/// Returns the first existing binary found. On failure, returns every
+/// path that was probed so the caller can surface a clear error.
+pub fn detect(channel: Option<BrowserChannel>) -> Result<PathBuf, Vec<String>> {
+    let mut probed = Vec::new();
+
+    if cfg!(target_os = "windows") {
+        for key in windows_registry_keys(channel) {
+            if let Some(path) = read_registry_app_path(key) {
+                if path.is_file() {
+                    return Ok(path);
+                }
+                probed.push(path.display().to_string());
+            }
+        }
+    } else if cfg!(target_os = "macos") {
+        for path in macos_candidates(channel) {
+            if path.is_file() {
+                return Ok(path);
+            }
+            probed.push(path.display().to_string());
+        }
+    } else {
+        for name in linux_candidates(channel) {
+            match which(name) {
+                Some(path) => return Ok(path),
+                None => probed.push(format!("{name} (not on $PATH)")),
+            }
+        }
+        for path in linux_standard_paths(channel) {
+            if path.is_file() {
+                return Ok(path);
+            }
+            probed.push(path.display().to_string());
+        }
+    }
+
+    Err(probed)
+}
+
+/// Formats a detection failure into the error shown to the user, listing
+/// every path (or, for `$PATH` lookups that missed, binary name) that was
+/// probed.
+pub fn format_not_found_error(probed: &[String]) -> String {
+    if probed.is_empty() {
+        return "could not find a Chrome/Chromium installation: no known install locations exist for this platform; pass --executable-path explicitly".to_string();
+    }
+    format!(
+        "could not find a Chrome/Chromium installation; probed the following paths:\n  {}\npass --executable-path to specify one explicitly",
+        probed.join("\n  ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channel() {
+        assert_eq!(BrowserChannel::parse("Stable"), Some(BrowserChannel::Stable));
+        assert_eq!(BrowserChannel::parse("canary"), Some(BrowserChannel::Canary));
+        assert_eq!(BrowserChannel::parse("nonsense"), None);
+    }
+
+    #[test]
+    fn test_format_not_found_error_lists_probed_paths() {
+        let probed = vec!["/usr/bin/google-chrome".to_string(), "/usr/bin/chromium".to_string()];
+        let message = format_not_found_error(&probed);
+        assert!(message.contains("/usr/bin/google-chrome"));
+        assert!(message.contains("/usr/bin/chromium"));
+        assert!(message.contains("--executable-path"));
+    }
+
+    #[test]
+    fn test_format_not_found_error_empty_probe_list() {
+        let message = format_not_found_error(&[]);
+        assert!(message.contains("--executable-path"));
+    }
+
+    #[test]
+    fn test_format_not_found_error_labels_path_misses() {
+        let probed = vec!["google-chrome (not on $PATH)".to_string()];
+        let message = format_not_found_error(&probed);
+        assert!(message.contains("google-chrome (not on $PATH)"));
+    }
+
+    #[test]
+    fn test_parse_reg_query_output_extracts_value_after_reg_sz() {
+        let stdout = "\r\nHKEY_LOCAL_MACHINE\\SOFTWARE\\...\\chrome.exe\r\n    (Default)    REG_SZ    C:\\Program Files\\Google\\Chrome\\Application\\chrome.exe\r\n\r\n";
+        let path = parse_reg_query_output(stdout).expect("should parse a path");
+        assert_eq!(path, PathBuf::from(r"C:\Program Files\Google\Chrome\Application\chrome.exe"));
+    }
+
+    #[test]
+    fn test_parse_reg_query_output_no_match_returns_none() {
+        assert!(parse_reg_query_output("ERROR: The system was unable to find the specified registry key.").is_none());
+    }
+}